@@ -0,0 +1,210 @@
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// The load ordering implied by an RMW ordering, mirroring the mapping std uses
+/// internally: a `Release`/`AcqRel` RMW has no load-side release, so the CAS
+/// loop reads with `Relaxed`/`Acquire` respectively while the store keeps the
+/// full ordering. Without this, forwarding `Release` into a load would panic.
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        other => other,
+    }
+}
+
+/// A lock-free `f32` whose storage is reinterpreted as an [`AtomicU32`].
+///
+/// Every operation routes through the raw bit pattern: `store` bit-casts with
+/// `f32::to_bits`, `load` reconstructs with `f32::from_bits`. Comparisons in
+/// `compare_exchange` are therefore *bitwise*, not IEEE-754: `NaN` payloads are
+/// significant and `-0.0` does not compare equal to `+0.0`.
+pub struct AtomicF32 {
+    bits: AtomicU32,
+}
+
+impl AtomicF32 {
+    pub const fn new(v: f32) -> Self {
+        Self { bits: AtomicU32::new(v.to_bits()) }
+    }
+
+    pub fn store(&self, v: f32, order: Ordering) {
+        self.bits.store(v.to_bits(), order);
+    }
+
+    pub fn load(&self, order: Ordering) -> f32 {
+        f32::from_bits(self.bits.load(order))
+    }
+
+    pub fn swap(&self, v: f32, order: Ordering) -> f32 {
+        f32::from_bits(self.bits.swap(v.to_bits(), order))
+    }
+
+    /// Bitwise compare-and-swap. `current` and the stored value are compared as
+    /// raw `u32`s, so `NaN`/`-0.0` follow bit-pattern equality.
+    pub fn compare_exchange(
+        &self,
+        current: f32,
+        new: f32,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<f32, f32> {
+        match self
+            .bits
+            .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+        {
+            Ok(prev) => Ok(f32::from_bits(prev)),
+            Err(prev) => Err(f32::from_bits(prev)),
+        }
+    }
+
+    /// CAS-loop helper mirroring `AtomicU32::fetch_update`; there is no native
+    /// float RMW so every arithmetic update is synthesized this way.
+    pub fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<f32, f32>
+    where
+        F: FnMut(f32) -> Option<f32>,
+    {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = f(prev) {
+            match self.compare_exchange(prev, next, set_order, fetch_order) {
+                Ok(x) => return Ok(x),
+                Err(x) => prev = x,
+            }
+        }
+        Err(prev)
+    }
+
+    pub fn fetch_add(&self, v: f32, order: Ordering) -> f32 {
+        self.fetch_update(order, load_ordering(order), |x| Some(x + v)).unwrap()
+    }
+
+    pub fn fetch_max(&self, v: f32, order: Ordering) -> f32 {
+        self.fetch_update(order, load_ordering(order), |x| Some(x.max(v))).unwrap()
+    }
+}
+
+/// A lock-free `f64` reinterpreted as an [`AtomicU64`]; see [`AtomicF32`] for
+/// the bitwise-comparison caveats.
+pub struct AtomicF64 {
+    bits: AtomicU64,
+}
+
+impl AtomicF64 {
+    pub const fn new(v: f64) -> Self {
+        Self { bits: AtomicU64::new(v.to_bits()) }
+    }
+
+    pub fn store(&self, v: f64, order: Ordering) {
+        self.bits.store(v.to_bits(), order);
+    }
+
+    pub fn load(&self, order: Ordering) -> f64 {
+        f64::from_bits(self.bits.load(order))
+    }
+
+    pub fn swap(&self, v: f64, order: Ordering) -> f64 {
+        f64::from_bits(self.bits.swap(v.to_bits(), order))
+    }
+
+    pub fn compare_exchange(
+        &self,
+        current: f64,
+        new: f64,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<f64, f64> {
+        match self
+            .bits
+            .compare_exchange(current.to_bits(), new.to_bits(), success, failure)
+        {
+            Ok(prev) => Ok(f64::from_bits(prev)),
+            Err(prev) => Err(f64::from_bits(prev)),
+        }
+    }
+
+    pub fn fetch_update<F>(
+        &self,
+        set_order: Ordering,
+        fetch_order: Ordering,
+        mut f: F,
+    ) -> Result<f64, f64>
+    where
+        F: FnMut(f64) -> Option<f64>,
+    {
+        let mut prev = self.load(fetch_order);
+        while let Some(next) = f(prev) {
+            match self.compare_exchange(prev, next, set_order, fetch_order) {
+                Ok(x) => return Ok(x),
+                Err(x) => prev = x,
+            }
+        }
+        Err(prev)
+    }
+
+    pub fn fetch_add(&self, v: f64, order: Ordering) -> f64 {
+        self.fetch_update(order, load_ordering(order), |x| Some(x + v)).unwrap()
+    }
+}
+
+fn main() {
+    // Test 1: Basic load/store round-trips through the bit pattern.
+    let dt = AtomicF32::new(0.0);
+    dt.store(1.0 / 60.0, Ordering::SeqCst);
+    assert_eq!(dt.load(Ordering::SeqCst), 1.0 / 60.0, "f32 load/store");
+
+    // Test 2: swap returns the previous value.
+    assert_eq!(dt.swap(0.25, Ordering::SeqCst), 1.0 / 60.0, "f32 swap prev");
+    assert_eq!(dt.load(Ordering::SeqCst), 0.25, "f32 swap value");
+
+    // Test 3: CAS compares bit patterns. +0.0 and -0.0 are distinct bitwise.
+    let z = AtomicF32::new(0.0);
+    assert_eq!(
+        z.compare_exchange(-0.0, 1.0, Ordering::SeqCst, Ordering::SeqCst),
+        Err(0.0),
+        "+0.0 must not match -0.0 bitwise"
+    );
+    assert_eq!(
+        z.compare_exchange(0.0, 1.0, Ordering::SeqCst, Ordering::SeqCst),
+        Ok(0.0),
+        "+0.0 matches +0.0"
+    );
+
+    // Test 4: CAS-loop arithmetic (no native float RMW).
+    let acc = AtomicF32::new(0.0);
+    assert_eq!(acc.fetch_add(1.5, Ordering::SeqCst), 0.0, "f32 fetch_add prev");
+    assert_eq!(acc.fetch_add(2.0, Ordering::SeqCst), 1.5, "f32 fetch_add prev 2");
+    assert_eq!(acc.load(Ordering::SeqCst), 3.5, "f32 fetch_add value");
+    assert_eq!(acc.fetch_max(2.0, Ordering::SeqCst), 3.5, "f32 fetch_max keeps larger");
+    assert_eq!(acc.load(Ordering::SeqCst), 3.5, "f32 fetch_max value");
+
+    // Test 5: NaN payloads survive the round-trip bit-for-bit.
+    let nan = f32::from_bits(0x7FC0_1234);
+    let n = AtomicF32::new(nan);
+    assert_eq!(n.load(Ordering::SeqCst).to_bits(), 0x7FC0_1234, "NaN payload preserved");
+
+    // Test 6: f64 surface.
+    let d = AtomicF64::new(1.0);
+    assert_eq!(d.fetch_add(0.5, Ordering::SeqCst), 1.0, "f64 fetch_add prev");
+    assert_eq!(d.load(Ordering::SeqCst), 1.5, "f64 fetch_add value");
+    assert_eq!(
+        d.compare_exchange(1.5, 2.0, Ordering::AcqRel, Ordering::Acquire),
+        Ok(1.5),
+        "f64 CAS success"
+    );
+
+    // Test 7: RMW ops must accept every ordering without panicking, including
+    // the release-family orderings that have no valid load form.
+    let r = AtomicF32::new(1.0);
+    assert_eq!(r.fetch_add(1.0, Ordering::Release), 1.0, "f32 fetch_add Release prev");
+    assert_eq!(r.fetch_max(5.0, Ordering::AcqRel), 2.0, "f32 fetch_max AcqRel prev");
+    assert_eq!(r.load(Ordering::SeqCst), 5.0, "f32 RMW value after release-family");
+    let rd = AtomicF64::new(1.0);
+    assert_eq!(rd.fetch_add(1.0, Ordering::Release), 1.0, "f64 fetch_add Release prev");
+    assert_eq!(rd.load(Ordering::SeqCst), 2.0, "f64 fetch_add Release value");
+
+    println!("All atomic tests passed!");
+}
@@ -0,0 +1,289 @@
+use std::cell::UnsafeCell;
+use std::mem::{self, align_of, size_of};
+use std::num::NonZeroU32;
+use std::sync::atomic::{AtomicBool, AtomicU16, AtomicU32, AtomicU64, AtomicU8, Ordering};
+
+/// Number of spinlock shards backing the locked fallback. A power of two so the
+/// address key reduces to a mask.
+const SHARDS: usize = 64;
+
+static LOCKS: [AtomicBool; SHARDS] = [const { AtomicBool::new(false) }; SHARDS];
+
+/// Map a storage address to one of the [`LOCKS`] shards.
+fn shard_for(addr: usize) -> &'static AtomicBool {
+    // Drop the low bits (all small allocations share alignment) before masking.
+    &LOCKS[(addr >> 3) & (SHARDS - 1)]
+}
+
+struct ShardGuard(&'static AtomicBool);
+
+impl ShardGuard {
+    fn acquire(addr: usize) -> Self {
+        let lock = shard_for(addr);
+        while lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+        ShardGuard(lock)
+    }
+}
+
+impl Drop for ShardGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::Release);
+    }
+}
+
+/// Is `T` small and aligned enough to be backed by a native integer atomic?
+const fn lock_free<T>() -> bool {
+    let size = size_of::<T>();
+    matches!(size, 1 | 2 | 4 | 8) && align_of::<T>() >= size
+}
+
+/// A generic atomic cell over any `T: Copy`.
+///
+/// When `T` is 1/2/4/8 bytes and naturally aligned the operations lower to the
+/// matching integer atomic (the value is reinterpreted bit-for-bit). Otherwise
+/// the cell falls back to a sharded spinlock keyed by its address, so the API
+/// stays uniform regardless of `T`. Use [`Atomic::is_lock_free`] to branch on
+/// the guarantee. Comparisons in `compare_exchange` are bitwise.
+pub struct Atomic<T> {
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: all access goes through atomic integer ops or a spinlock, so the cell
+// is safe to share between threads when `T` can cross a thread boundary.
+unsafe impl<T: Copy + Send> Sync for Atomic<T> {}
+
+impl<T: Copy> Atomic<T> {
+    pub const fn new(v: T) -> Self {
+        Self { value: UnsafeCell::new(v) }
+    }
+
+    /// Whether operations on this type are lock-free on the current target.
+    pub const fn is_lock_free() -> bool {
+        lock_free::<T>()
+    }
+
+    pub fn load(&self, order: Ordering) -> T {
+        let dst = self.value.get();
+        if lock_free::<T>() {
+            // SAFETY: size/alignment were checked by `lock_free`.
+            unsafe { load_lock_free(dst, order) }
+        } else {
+            let _guard = ShardGuard::acquire(dst as usize);
+            // SAFETY: the shard guard grants exclusive access to `dst`.
+            unsafe { *dst }
+        }
+    }
+
+    pub fn store(&self, v: T, order: Ordering) {
+        let dst = self.value.get();
+        if lock_free::<T>() {
+            // SAFETY: see `load`.
+            unsafe { store_lock_free(dst, v, order) }
+        } else {
+            let _guard = ShardGuard::acquire(dst as usize);
+            // SAFETY: exclusive access held.
+            unsafe { *dst = v }
+        }
+    }
+
+    pub fn swap(&self, v: T, order: Ordering) -> T {
+        let dst = self.value.get();
+        if lock_free::<T>() {
+            // SAFETY: see `load`.
+            unsafe { swap_lock_free(dst, v, order) }
+        } else {
+            let _guard = ShardGuard::acquire(dst as usize);
+            // SAFETY: exclusive access held.
+            unsafe {
+                let prev = *dst;
+                *dst = v;
+                prev
+            }
+        }
+    }
+
+    /// Bitwise compare-and-swap. Returns `Ok(current)` on success or
+    /// `Err(actual)` when the stored bits differ from `current`.
+    pub fn compare_exchange(
+        &self,
+        current: T,
+        new: T,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<T, T> {
+        let dst = self.value.get();
+        if lock_free::<T>() {
+            // SAFETY: see `load`.
+            unsafe { compare_exchange_lock_free(dst, current, new, success, failure) }
+        } else {
+            let _guard = ShardGuard::acquire(dst as usize);
+            // SAFETY: exclusive access held.
+            unsafe {
+                let prev = *dst;
+                if bytes_eq(&prev, &current) {
+                    *dst = new;
+                    Ok(prev)
+                } else {
+                    Err(prev)
+                }
+            }
+        }
+    }
+}
+
+/// Compare two `Copy` values by their raw bytes.
+unsafe fn bytes_eq<T>(a: &T, b: &T) -> bool {
+    let a = std::slice::from_raw_parts(a as *const T as *const u8, size_of::<T>());
+    let b = std::slice::from_raw_parts(b as *const T as *const u8, size_of::<T>());
+    a == b
+}
+
+// The lock-free helpers dispatch on size. `transmute_copy` reinterprets exactly
+// `size_of::<T>()` bytes, which matches the selected integer in each arm.
+macro_rules! dispatch {
+    ($size:expr, $u8:expr, $u16:expr, $u32:expr, $u64:expr) => {
+        match $size {
+            1 => $u8,
+            2 => $u16,
+            4 => $u32,
+            8 => $u64,
+            _ => unreachable!("lock_free guaranteed a supported width"),
+        }
+    };
+}
+
+unsafe fn load_lock_free<T: Copy>(dst: *const T, order: Ordering) -> T {
+    dispatch!(
+        size_of::<T>(),
+        from_int::<T, u8>((*(dst as *const AtomicU8)).load(order)),
+        from_int::<T, u16>((*(dst as *const AtomicU16)).load(order)),
+        from_int::<T, u32>((*(dst as *const AtomicU32)).load(order)),
+        from_int::<T, u64>((*(dst as *const AtomicU64)).load(order))
+    )
+}
+
+unsafe fn store_lock_free<T: Copy>(dst: *mut T, v: T, order: Ordering) {
+    dispatch!(
+        size_of::<T>(),
+        (*(dst as *const AtomicU8)).store(to_int::<T, u8>(v), order),
+        (*(dst as *const AtomicU16)).store(to_int::<T, u16>(v), order),
+        (*(dst as *const AtomicU32)).store(to_int::<T, u32>(v), order),
+        (*(dst as *const AtomicU64)).store(to_int::<T, u64>(v), order)
+    )
+}
+
+unsafe fn swap_lock_free<T: Copy>(dst: *mut T, v: T, order: Ordering) -> T {
+    dispatch!(
+        size_of::<T>(),
+        from_int::<T, u8>((*(dst as *const AtomicU8)).swap(to_int::<T, u8>(v), order)),
+        from_int::<T, u16>((*(dst as *const AtomicU16)).swap(to_int::<T, u16>(v), order)),
+        from_int::<T, u32>((*(dst as *const AtomicU32)).swap(to_int::<T, u32>(v), order)),
+        from_int::<T, u64>((*(dst as *const AtomicU64)).swap(to_int::<T, u64>(v), order))
+    )
+}
+
+unsafe fn compare_exchange_lock_free<T: Copy>(
+    dst: *mut T,
+    current: T,
+    new: T,
+    success: Ordering,
+    failure: Ordering,
+) -> Result<T, T> {
+    macro_rules! cas {
+        ($int:ty, $atomic:ty) => {{
+            let a = &*(dst as *const $atomic);
+            match a.compare_exchange(
+                to_int::<T, $int>(current),
+                to_int::<T, $int>(new),
+                success,
+                failure,
+            ) {
+                Ok(prev) => Ok(from_int::<T, $int>(prev)),
+                Err(prev) => Err(from_int::<T, $int>(prev)),
+            }
+        }};
+    }
+    dispatch!(
+        size_of::<T>(),
+        cas!(u8, AtomicU8),
+        cas!(u16, AtomicU16),
+        cas!(u32, AtomicU32),
+        cas!(u64, AtomicU64)
+    )
+}
+
+/// Reinterpret `T` as the backing integer (sizes match in every call site).
+unsafe fn to_int<T: Copy, I: Copy>(v: T) -> I {
+    mem::transmute_copy(&v)
+}
+
+/// Reinterpret the backing integer back into `T`.
+unsafe fn from_int<T: Copy, I: Copy>(v: I) -> T {
+    mem::transmute_copy(&v)
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[repr(u8)]
+enum State {
+    Idle = 0,
+    Running = 1,
+    Done = 2,
+}
+
+// A 12-byte struct: too wide for a native atomic, so it uses the locked path.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Wide {
+    a: u32,
+    b: u32,
+    c: u32,
+}
+
+fn main() {
+    // Test 1: Small enum — lock-free.
+    assert!(Atomic::<State>::is_lock_free(), "1-byte enum should be lock-free");
+    let s = Atomic::new(State::Idle);
+    assert_eq!(s.load(Ordering::SeqCst), State::Idle, "enum load");
+    assert_eq!(s.swap(State::Running, Ordering::SeqCst), State::Idle, "enum swap prev");
+    assert_eq!(
+        s.compare_exchange(State::Running, State::Done, Ordering::AcqRel, Ordering::Acquire),
+        Ok(State::Running),
+        "enum CAS success"
+    );
+    assert_eq!(
+        s.compare_exchange(State::Idle, State::Running, Ordering::AcqRel, Ordering::Acquire),
+        Err(State::Done),
+        "enum CAS failure returns actual"
+    );
+
+    // Test 2: Niche-optimized Option<NonZeroU32> — 4 bytes, lock-free.
+    assert!(Atomic::<Option<NonZeroU32>>::is_lock_free(), "Option<NonZero> lock-free");
+    let o = Atomic::<Option<NonZeroU32>>::new(None);
+    assert_eq!(o.load(Ordering::SeqCst), None, "none load");
+    o.store(NonZeroU32::new(7), Ordering::SeqCst);
+    assert_eq!(o.load(Ordering::SeqCst), NonZeroU32::new(7), "some load");
+
+    // Test 3: Wide struct — falls back to the sharded spinlock.
+    assert!(!Atomic::<Wide>::is_lock_free(), "12-byte struct is not lock-free");
+    let w = Atomic::new(Wide { a: 1, b: 2, c: 3 });
+    assert_eq!(w.load(Ordering::SeqCst), Wide { a: 1, b: 2, c: 3 }, "wide load");
+    let prev = w.swap(Wide { a: 4, b: 5, c: 6 }, Ordering::SeqCst);
+    assert_eq!(prev, Wide { a: 1, b: 2, c: 3 }, "wide swap prev");
+    assert_eq!(
+        w.compare_exchange(
+            Wide { a: 4, b: 5, c: 6 },
+            Wide { a: 7, b: 8, c: 9 },
+            Ordering::SeqCst,
+            Ordering::SeqCst
+        ),
+        Ok(Wide { a: 4, b: 5, c: 6 }),
+        "wide CAS success"
+    );
+    assert_eq!(w.load(Ordering::SeqCst), Wide { a: 7, b: 8, c: 9 }, "wide CAS value");
+
+    println!("All atomic tests passed!");
+}
@@ -0,0 +1,51 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Negative fixture for zwasm's opt-in weak-memory data-race detector.
+//
+// Unlike the positive `rust-atomic-weakmem` fixture, this program is NOT run as
+// an ordinary native binary and does NOT print the success banner: its expected
+// output is the detector's race diagnostic. It is meant to be interpreted with
+// the checker enabled, where the two unsynchronized accesses below are modeled
+// as memory events and their vector clocks compared — the raced accesses never
+// execute as real concurrent native reads/writes, so there is no reliance on
+// UB happening to yield a benign value.
+//
+// The race: a writer thread stores to a non-atomic location while a reader
+// thread loads the same location, with NO happens-before edge between them (no
+// Release/Acquire pair, no flag handshake). The two accesses are unordered in
+// the partial order and one is a write, so the detector must abort and pinpoint
+// the write and the read as the racing pair.
+
+/// A non-atomic `i32` cell shared across threads with no synchronization. The
+/// unsafe `Sync` is exactly the unsoundness the detector exists to catch.
+struct Racy(UnsafeCell<i32>);
+unsafe impl Sync for Racy {}
+
+static RACY: Racy = Racy(UnsafeCell::new(0));
+
+// A separately published atomic flag is present only to make clear that it is
+// *not* used to order the two accesses; the reader never waits on it.
+static UNUSED_FLAG: AtomicBool = AtomicBool::new(false);
+
+fn main() {
+    std::thread::scope(|scope| {
+        // Writer: raise an unrelated flag, then write the payload. Nothing the
+        // reader does observes this flag, so the write stays unordered with the
+        // read below.
+        scope.spawn(|| {
+            UNUSED_FLAG.store(true, Ordering::Release);
+            unsafe { *RACY.0.get() = 99 };
+        });
+        // Reader: read the payload without acquiring the flag or any other
+        // synchronization. This is the racing access.
+        scope.spawn(|| {
+            let _ = unsafe { *RACY.0.get() };
+        });
+    });
+
+    // Reaching here under the detector means it failed to report the race; the
+    // intended outcome is a diagnostic abort inside the scope above.
+    eprintln!("detector did not flag the data race");
+    std::process::exit(1);
+}
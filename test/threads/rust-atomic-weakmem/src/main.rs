@@ -0,0 +1,72 @@
+use std::sync::atomic::{AtomicBool, AtomicI32, Ordering};
+
+// Conformance test for zwasm's opt-in weak-memory data-race detector.
+//
+// The detector (in the interpreter's memory model, not this program) gives each
+// shared location a bounded store history of `{value, store_clock, store_index}`
+// entries and a per-thread vector clock: a Release store stamps its entry with
+// the writer's clock, an Acquire load joins the loaded entry's clock into the
+// reader's. Two accesses to one location that are unordered in that partial
+// order, at least one a write, are a data race.
+//
+// The patterns below are the ones the detector must classify correctly; run
+// natively they simply execute under sequential consistency and must pass.
+
+static FLAG: AtomicBool = AtomicBool::new(false);
+static DATA: AtomicI32 = AtomicI32::new(0);
+
+fn main() {
+    // Test 1: Release/Acquire message passing establishes happens-before. The
+    // non-atomic-style payload write is ordered before the flag raise, and the
+    // reader's acquire load of the flag joins that clock, so reading DATA after
+    // seeing the flag is race-free. The detector must NOT flag this.
+    FLAG.store(false, Ordering::SeqCst);
+    DATA.store(0, Ordering::SeqCst);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            DATA.store(1234, Ordering::Relaxed);
+            FLAG.store(true, Ordering::Release);
+        });
+        while !FLAG.load(Ordering::Acquire) {
+            std::hint::spin_loop();
+        }
+        // Happens-after the release store: the only value in DATA's history the
+        // reader may observe is the one published before the flag.
+        assert_eq!(DATA.load(Ordering::Relaxed), 1234, "release/acquire handoff");
+    });
+
+    // Test 2: A relaxed load is permitted to observe a stale history entry that
+    // is not happens-before-dominated by the reader's clock. Here we only assert
+    // the value lands in the set the model allows {0, 10, 30}; a SeqCst reader
+    // would instead be pinned to the global total order.
+    DATA.store(0, Ordering::SeqCst);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            DATA.fetch_add(10, Ordering::Relaxed);
+            DATA.fetch_add(20, Ordering::Relaxed);
+        });
+        let seen = DATA.load(Ordering::Relaxed);
+        assert!(matches!(seen, 0 | 10 | 30), "relaxed load saw an impossible value");
+    });
+    assert_eq!(DATA.load(Ordering::SeqCst), 30, "final value after join");
+
+    // Test 3: SeqCst stores and loads for the handoff give a single total order, the
+    // strongest case the detector consults its global order for.
+    FLAG.store(false, Ordering::SeqCst);
+    DATA.store(0, Ordering::SeqCst);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            DATA.store(7, Ordering::SeqCst);
+            FLAG.store(true, Ordering::SeqCst);
+        });
+        while !FLAG.load(Ordering::SeqCst) {
+            std::hint::spin_loop();
+        }
+        assert_eq!(DATA.load(Ordering::SeqCst), 7, "seqcst handoff");
+    });
+
+    // The negative case — an unsynchronized write/read the detector MUST flag —
+    // lives in the sibling `rust-atomic-weakmem-race` fixture, whose expected
+    // output is the race diagnostic rather than this success banner.
+    println!("All atomic tests passed!");
+}
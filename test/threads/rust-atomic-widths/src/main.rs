@@ -0,0 +1,62 @@
+use std::sync::atomic::{
+    AtomicI16, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64, AtomicU8,
+    AtomicUsize, Ordering,
+};
+
+fn main() {
+    // Test 1: Subword widths (8/16 bit). On WASM these lower to a 32-bit
+    // aligned cmpxchg loop that masks the target byte(s) into place, so the
+    // observable semantics must match the full-width ops exactly.
+    let a8 = AtomicI8::new(0);
+    a8.store(-5, Ordering::SeqCst);
+    assert_eq!(a8.load(Ordering::SeqCst), -5, "i8 load/store");
+    assert_eq!(a8.fetch_add(2, Ordering::SeqCst), -5, "i8 fetch_add prev");
+    assert_eq!(a8.load(Ordering::SeqCst), -3, "i8 fetch_add value");
+
+    let u8a = AtomicU8::new(0xFE);
+    assert_eq!(u8a.fetch_add(1, Ordering::SeqCst), 0xFE, "u8 fetch_add prev");
+    assert_eq!(u8a.fetch_add(1, Ordering::SeqCst), 0xFF, "u8 wraps");
+    assert_eq!(u8a.load(Ordering::SeqCst), 0x00, "u8 wrapped to zero");
+
+    let a16 = AtomicI16::new(1000);
+    assert_eq!(a16.swap(-1000, Ordering::SeqCst), 1000, "i16 swap prev");
+    assert_eq!(a16.load(Ordering::SeqCst), -1000, "i16 swap value");
+
+    let u16a = AtomicU16::new(0b1010_1010_1010_1010);
+    assert_eq!(u16a.fetch_and(0xFF00, Ordering::SeqCst), 0b1010_1010_1010_1010, "u16 and prev");
+    assert_eq!(u16a.load(Ordering::SeqCst), 0b1010_1010_0000_0000, "u16 and value");
+
+    // Test 2: 32-bit unsigned, the natively-sized case.
+    let u32a = AtomicU32::new(100);
+    assert_eq!(
+        u32a.compare_exchange(100, 200, Ordering::SeqCst, Ordering::SeqCst),
+        Ok(100),
+        "u32 CAS success"
+    );
+    assert_eq!(
+        u32a.compare_exchange(100, 300, Ordering::SeqCst, Ordering::SeqCst),
+        Err(200),
+        "u32 CAS failure returns current"
+    );
+
+    // Test 3: 64-bit. On WASM these map to i64.atomic.* on shared memory and
+    // still require 8-byte alignment even on 32-bit targets.
+    let a64 = AtomicI64::new(0);
+    assert_eq!(a64.fetch_add(1 << 40, Ordering::SeqCst), 0, "i64 fetch_add prev");
+    assert_eq!(a64.load(Ordering::SeqCst), 1 << 40, "i64 holds full width");
+
+    let u64a = AtomicU64::new(u64::MAX);
+    assert_eq!(u64a.fetch_min(1, Ordering::SeqCst), u64::MAX, "u64 min prev");
+    assert_eq!(u64a.load(Ordering::SeqCst), 1, "u64 min value");
+
+    // Test 4: Pointer-sized atomics.
+    let isz = AtomicIsize::new(-42);
+    assert_eq!(isz.fetch_max(7, Ordering::SeqCst), -42, "isize max prev");
+    assert_eq!(isz.load(Ordering::SeqCst), 7, "isize max value");
+
+    let usz = AtomicUsize::new(0);
+    assert_eq!(usz.fetch_or(0b101, Ordering::SeqCst), 0, "usize or prev");
+    assert_eq!(usz.load(Ordering::SeqCst), 0b101, "usize or value");
+
+    println!("All atomic tests passed!");
+}
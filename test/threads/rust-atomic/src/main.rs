@@ -1,4 +1,4 @@
-use std::sync::atomic::{AtomicI32, Ordering};
+use std::sync::atomic::{compiler_fence, fence, AtomicBool, AtomicI32, Ordering};
 
 static COUNTER: AtomicI32 = AtomicI32::new(0);
 
@@ -26,5 +26,85 @@ fn main() {
     let result = COUNTER.compare_exchange(100, 300, Ordering::SeqCst, Ordering::SeqCst);
     assert_eq!(result, Err(200), "CAS should fail");
 
+    // Test 5: Weak CAS with independent success/failure orderings.
+    // compare_exchange_weak may fail spuriously, so it is always driven in a
+    // loop; here the comparison genuinely matches so the loop settles quickly.
+    COUNTER.store(200, Ordering::SeqCst);
+    loop {
+        match COUNTER.compare_exchange_weak(200, 201, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(prev) => {
+                assert_eq!(prev, 200, "weak CAS returned wrong previous value");
+                break;
+            }
+            // Spurious failure: the value is still what we expected, retry.
+            Err(cur) => assert_eq!(cur, 200, "weak CAS failed for the wrong reason"),
+        }
+    }
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 201, "weak CAS value wrong");
+
+    // Test 6: Failed weak CAS reports the current value like the strong form,
+    // and a relaxed failure ordering is accepted.
+    let result = COUNTER.compare_exchange_weak(100, 999, Ordering::Release, Ordering::Relaxed);
+    assert_eq!(result, Err(201), "failed weak CAS should return current value");
+
+    // Test 7: The read-modify-write family. Each operation returns the value
+    // held *before* the update was applied.
+    COUNTER.store(100, Ordering::SeqCst);
+    assert_eq!(COUNTER.fetch_add(10, Ordering::SeqCst), 100, "fetch_add prev");
+    assert_eq!(COUNTER.fetch_sub(30, Ordering::SeqCst), 110, "fetch_sub prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 80, "RMW add/sub value wrong");
+
+    // Bitwise RMW ops on a mask.
+    COUNTER.store(0b1100, Ordering::SeqCst);
+    assert_eq!(COUNTER.fetch_and(0b1010, Ordering::SeqCst), 0b1100, "fetch_and prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 0b1000, "fetch_and value");
+    assert_eq!(COUNTER.fetch_or(0b0011, Ordering::SeqCst), 0b1000, "fetch_or prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 0b1011, "fetch_or value");
+    assert_eq!(COUNTER.fetch_xor(0b1111, Ordering::SeqCst), 0b1011, "fetch_xor prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 0b0100, "fetch_xor value");
+    // fetch_nand computes !(old & val), so !(0b0100 & 0b0110) = !(0b0100) = -5.
+    assert_eq!(COUNTER.fetch_nand(0b0110, Ordering::SeqCst), 0b0100, "fetch_nand prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), !(0b0100 & 0b0110), "fetch_nand value");
+
+    // max/min track extrema; signedness matters for the comparison.
+    COUNTER.store(5, Ordering::SeqCst);
+    assert_eq!(COUNTER.fetch_max(3, Ordering::SeqCst), 5, "fetch_max prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 5, "fetch_max kept larger");
+    assert_eq!(COUNTER.fetch_max(9, Ordering::SeqCst), 5, "fetch_max prev 2");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 9, "fetch_max took larger");
+    assert_eq!(COUNTER.fetch_min(-1, Ordering::SeqCst), 9, "fetch_min prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), -1, "signed fetch_min took smaller");
+
+    // swap unconditionally writes and returns the old value.
+    assert_eq!(COUNTER.swap(77, Ordering::SeqCst), -1, "swap prev");
+    assert_eq!(COUNTER.load(Ordering::SeqCst), 77, "swap value");
+
+    // Test 8: Standalone fence establishing a release/acquire handoff where the
+    // payload itself is published with a plain (Relaxed) store. The producer's
+    // release fence orders the payload before raising the flag; the consumer's
+    // acquire fence orders the flag check before reading the payload.
+    static FLAG: AtomicBool = AtomicBool::new(false);
+    COUNTER.store(0, Ordering::Relaxed);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            COUNTER.store(4242, Ordering::Relaxed);
+            fence(Ordering::Release);
+            FLAG.store(true, Ordering::Relaxed);
+        });
+        // Spin until the flag is observed, then synchronize with the write.
+        while !FLAG.load(Ordering::Relaxed) {
+            std::hint::spin_loop();
+        }
+        fence(Ordering::Acquire);
+        assert_eq!(COUNTER.load(Ordering::Relaxed), 4242, "fence handoff failed");
+    });
+
+    // Test 9: compiler_fence emits no instruction but still forbids the backend
+    // from reordering the two stores across it within this thread.
+    COUNTER.store(1, Ordering::Relaxed);
+    compiler_fence(Ordering::SeqCst);
+    COUNTER.store(2, Ordering::Relaxed);
+    assert_eq!(COUNTER.load(Ordering::Relaxed), 2, "compiler_fence ordering");
+
     println!("All atomic tests passed!");
 }